@@ -32,21 +32,21 @@ fn main() -> io::Result<()> {
     let mut megabytes_ps: f64 = 0.0;
     for i in 0..count {
         let t0 = Instant::now();
-        let (buf, meta) = stream.next()?;
+        let (_buf, meta) = stream.next()?;
         let duration_us = t0.elapsed().as_micros();
 
         println!("Buffer");
         println!("  sequence  : {}", meta.sequence);
         println!("  timestamp : {}", meta.timestamp);
         println!("  flags     : {}", meta.flags);
-        use std::fs::File;
-        use std::os::fd::{FromRawFd, AsRawFd, IntoRawFd};
-        use memmap2;
-        let outf = unsafe { File::from_raw_fd(buf.as_raw_fd()) };
-        let outfmap = unsafe { memmap2::Mmap::map(&outf) }?;
-        println!("  length    : {}", outfmap.len());
-        
-        let cur = outfmap.len() as f64 / 1_048_576.0 * 1_000_000.0 / duration_us as f64;
+        // Plane metadata (length, bytesused) comes straight from the
+        // v4l2_buffer/v4l2_plane the driver filled in, so there's no need
+        // to mmap the dmabuf fd just to learn how big the frame is.
+        let length: u32 = meta.planes.iter().map(|plane| plane.length).sum();
+        println!("  planes    : {}", meta.planes.len());
+        println!("  length    : {}", length);
+
+        let cur = length as f64 / 1_048_576.0 * 1_000_000.0 / duration_us as f64;
         if i == 0 {
             megabytes_ps = cur;
         } else {
@@ -55,8 +55,6 @@ fn main() -> io::Result<()> {
             let now = cur * (1.0 / (i + 1) as f64);
             megabytes_ps = prev + now;
         }
-        // Prevent File from dropping and closing the fd, because fd is borrowed from texture and not ours to close.
-        let _ = File::into_raw_fd(outf);
     }
 
     println!();
@@ -0,0 +1,113 @@
+use v4l2_sys::{
+    v4l2_buffer, v4l2_plane, V4L2_BUF_TYPE_VIDEO_CAPTURE, V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE,
+};
+
+use crate::io::plane::{PlaneDescriptor, PlaneMemory};
+
+/// Out-of-band information about a captured buffer, returned alongside the
+/// buffer itself by `CaptureStream::next`/`DmabufStream::next`
+#[derive(Debug, Clone)]
+pub struct Metadata {
+    /// Sequence number, as counted by the driver
+    pub sequence: u32,
+    /// Buffer timestamp, in microseconds
+    pub timestamp: u64,
+    /// Raw `v4l2_buffer` flags (`V4L2_BUF_FLAG_*`)
+    pub flags: u32,
+    /// Per-plane layout of the buffer
+    ///
+    /// Single-planar formats report exactly one descriptor. Multiplanar
+    /// formats (`V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE`, e.g. NV12 or
+    /// YUV420M on SoC ISPs) report one descriptor per `v4l2_plane`, in the
+    /// order the driver filled `v4l2_buffer.m.planes`.
+    pub planes: Vec<PlaneDescriptor>,
+}
+
+impl Metadata {
+    /// Builds metadata for a buffer dequeued from a non-multiplanar queue
+    ///
+    /// `fd_or_offset` is the dmabuf fd or mmap offset backing the buffer's
+    /// single implicit plane.
+    pub(crate) fn from_v4l2_buffer(buf: &v4l2_buffer, fd_or_offset: PlaneMemory) -> Self {
+        debug_assert_ne!(buf.type_, V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE);
+        Metadata {
+            sequence: buf.sequence,
+            timestamp: timestamp_us(buf),
+            flags: buf.flags,
+            planes: vec![PlaneDescriptor::from_v4l2_buffer(buf, fd_or_offset)],
+        }
+    }
+
+    /// Builds metadata for a buffer dequeued from a
+    /// `V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE` queue
+    ///
+    /// `planes` is the `v4l2_buffer.m.planes` array the driver filled in;
+    /// `fd_or_offset_for_plane` resolves the dmabuf fd or mmap offset
+    /// backing each plane by index.
+    pub(crate) fn from_v4l2_buffer_mplane(
+        buf: &v4l2_buffer,
+        planes: &[v4l2_plane],
+        fd_or_offset_for_plane: impl Fn(usize) -> PlaneMemory,
+    ) -> Self {
+        debug_assert_eq!(buf.type_, V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE);
+        Metadata {
+            sequence: buf.sequence,
+            timestamp: timestamp_us(buf),
+            flags: buf.flags,
+            planes: planes
+                .iter()
+                .enumerate()
+                .map(|(i, plane)| {
+                    PlaneDescriptor::from_v4l2_plane(plane, fd_or_offset_for_plane(i))
+                })
+                .collect(),
+        }
+    }
+}
+
+fn timestamp_us(buf: &v4l2_buffer) -> u64 {
+    buf.timestamp.tv_sec as u64 * 1_000_000 + buf.timestamp.tv_usec as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn zeroed_buffer(type_: u32) -> v4l2_buffer {
+        let mut buf: v4l2_buffer = unsafe { std::mem::zeroed() };
+        buf.type_ = type_;
+        buf.sequence = 7;
+        buf.bytesused = 1024;
+        buf.length = 2048;
+        buf
+    }
+
+    #[test]
+    fn single_plane_metadata_carries_one_descriptor() {
+        let buf = zeroed_buffer(V4L2_BUF_TYPE_VIDEO_CAPTURE);
+        let meta = Metadata::from_v4l2_buffer(&buf, PlaneMemory::Fd(3));
+        assert_eq!(meta.sequence, 7);
+        assert_eq!(meta.planes.len(), 1);
+        assert_eq!(meta.planes[0].bytesused, 1024);
+        assert_eq!(meta.planes[0].length, 2048);
+    }
+
+    #[test]
+    fn multiplanar_metadata_carries_one_descriptor_per_plane() {
+        let buf = zeroed_buffer(V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE);
+        let mut plane0: v4l2_plane = unsafe { std::mem::zeroed() };
+        plane0.bytesused = 640 * 480;
+        plane0.length = 640 * 480;
+        let mut plane1: v4l2_plane = unsafe { std::mem::zeroed() };
+        plane1.bytesused = 640 * 240;
+        plane1.length = 640 * 240;
+
+        let meta = Metadata::from_v4l2_buffer_mplane(&buf, &[plane0, plane1], |i| {
+            PlaneMemory::Fd(3 + i as i32)
+        });
+
+        assert_eq!(meta.planes.len(), 2);
+        assert_eq!(meta.planes[0].bytesused, 640 * 480);
+        assert_eq!(meta.planes[1].bytesused, 640 * 240);
+    }
+}
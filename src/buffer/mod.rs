@@ -0,0 +1,3 @@
+mod metadata;
+
+pub use metadata::Metadata;
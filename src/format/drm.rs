@@ -0,0 +1,164 @@
+use std::os::unix::io::RawFd;
+
+use crate::format::FourCC;
+
+/// DRM `fourcc` code, as defined by `drm_fourcc.h`
+///
+/// These are the codes GPU importers (EGL/Vulkan dmabuf import) expect when
+/// describing the layout of an imported buffer. They overlap with V4L2's own
+/// four character codes for many formats, but not all, and the two
+/// namespaces are not interchangeable without going through this mapping.
+pub type DrmFourCC = u32;
+
+pub const DRM_FORMAT_YUYV: DrmFourCC = fourcc_code(b'Y', b'U', b'Y', b'V');
+pub const DRM_FORMAT_YVYU: DrmFourCC = fourcc_code(b'Y', b'V', b'Y', b'U');
+pub const DRM_FORMAT_UYVY: DrmFourCC = fourcc_code(b'U', b'Y', b'V', b'Y');
+pub const DRM_FORMAT_VYUY: DrmFourCC = fourcc_code(b'V', b'Y', b'U', b'Y');
+pub const DRM_FORMAT_NV12: DrmFourCC = fourcc_code(b'N', b'V', b'1', b'2');
+pub const DRM_FORMAT_NV21: DrmFourCC = fourcc_code(b'N', b'V', b'2', b'1');
+pub const DRM_FORMAT_NV16: DrmFourCC = fourcc_code(b'N', b'V', b'1', b'6');
+pub const DRM_FORMAT_YUV420: DrmFourCC = fourcc_code(b'Y', b'U', b'1', b'2');
+pub const DRM_FORMAT_RGB888: DrmFourCC = fourcc_code(b'R', b'G', b'2', b'4');
+pub const DRM_FORMAT_BGR888: DrmFourCC = fourcc_code(b'B', b'G', b'2', b'4');
+pub const DRM_FORMAT_ARGB8888: DrmFourCC = fourcc_code(b'A', b'R', b'2', b'4');
+pub const DRM_FORMAT_XRGB8888: DrmFourCC = fourcc_code(b'X', b'R', b'2', b'4');
+pub const DRM_FORMAT_RGB565: DrmFourCC = fourcc_code(b'R', b'G', b'1', b'6');
+pub const DRM_FORMAT_MJPEG: DrmFourCC = fourcc_code(b'M', b'J', b'P', b'G');
+
+/// Packs four ASCII characters into a DRM fourcc code, the same way
+/// `drm_fourcc.h`'s `fourcc_code` macro does: `a` is the low byte, `d` the
+/// high byte.
+const fn fourcc_code(a: u8, b: u8, c: u8, d: u8) -> DrmFourCC {
+    (a as u32) | (b as u32) << 8 | (c as u32) << 16 | (d as u32) << 24
+}
+
+/// Static table mapping V4L2 [`FourCC`] codes to their DRM equivalents
+///
+/// Not every V4L2 format has a DRM counterpart (and vice versa), so lookups
+/// in either direction are fallible.
+///
+/// DRM format names describe a pixel's components from the high byte of a
+/// little-endian-stored word down to the low byte, which is the opposite of
+/// what the name suggests at a glance: `DRM_FORMAT_RGB888` is stored B,G,R
+/// in memory, while `DRM_FORMAT_BGR888` is stored R,G,B. Each entry below
+/// has been checked against the driver's actual in-memory byte order, not
+/// just name similarity; double check this against `drm_fourcc.h` before
+/// adding new entries.
+const V4L2_TO_DRM: &[(&[u8; 4], DrmFourCC)] = &[
+    (b"YUYV", DRM_FORMAT_YUYV),
+    (b"YVYU", DRM_FORMAT_YVYU),
+    (b"UYVY", DRM_FORMAT_UYVY),
+    (b"VYUY", DRM_FORMAT_VYUY),
+    (b"NV12", DRM_FORMAT_NV12),
+    (b"NV21", DRM_FORMAT_NV21),
+    (b"NV16", DRM_FORMAT_NV16),
+    (b"YU12", DRM_FORMAT_YUV420),
+    // V4L2_PIX_FMT_RGB24 is stored R,G,B, which is DRM_FORMAT_BGR888's
+    // memory layout, not DRM_FORMAT_RGB888's.
+    (b"RGB3", DRM_FORMAT_BGR888),
+    // Symmetrically, V4L2_PIX_FMT_BGR24 is stored B,G,R, matching
+    // DRM_FORMAT_RGB888.
+    (b"BGR3", DRM_FORMAT_RGB888),
+    // V4L2_PIX_FMT_ARGB32 is stored B,G,R,A, matching DRM_FORMAT_ARGB8888.
+    (b"BA24", DRM_FORMAT_ARGB8888),
+    (b"XR24", DRM_FORMAT_XRGB8888),
+    (b"RGBP", DRM_FORMAT_RGB565),
+    (b"MJPG", DRM_FORMAT_MJPEG),
+];
+
+/// Looks up the DRM `fourcc` code corresponding to a V4L2 [`FourCC`]
+///
+/// Returns `None` if the format has no known DRM equivalent.
+///
+/// # Example
+///
+/// ```
+/// use v4l::format::FourCC;
+/// use v4l::format::drm::{to_drm_fourcc, DRM_FORMAT_YUYV};
+/// assert_eq!(to_drm_fourcc(&FourCC::new(b"YUYV")), Some(DRM_FORMAT_YUYV));
+/// ```
+pub fn to_drm_fourcc(fourcc: &FourCC) -> Option<DrmFourCC> {
+    V4L2_TO_DRM
+        .iter()
+        .find(|(v4l2, _)| *v4l2 == &fourcc.repr)
+        .map(|(_, drm)| *drm)
+}
+
+/// Looks up the V4L2 [`FourCC`] corresponding to a DRM `fourcc` code
+///
+/// Returns `None` if the format has no known V4L2 equivalent.
+pub fn from_drm_fourcc(drm: DrmFourCC) -> Option<FourCC> {
+    V4L2_TO_DRM
+        .iter()
+        .find(|(_, candidate)| *candidate == drm)
+        .map(|(v4l2, _)| FourCC::new(v4l2))
+}
+
+/// DRM format modifier describing the physical tiling/compression layout of
+/// a buffer, e.g. `DRM_FORMAT_MOD_LINEAR`
+pub type DrmFormatModifier = u64;
+
+/// No tiling/compression; the buffer is a plain row-major linear image
+pub const DRM_FORMAT_MOD_LINEAR: DrmFormatModifier = 0;
+
+/// A dmabuf-exported buffer paired with the DRM format modifier describing
+/// its physical layout
+///
+/// The modifier is negotiated once per queue, so every buffer handed out by
+/// the same stream carries the same value; callers typically query it once
+/// (e.g. after the first successful `next()`) and reuse it for the rest of
+/// the stream's buffers.
+#[derive(Debug, Copy, Clone)]
+pub struct DmabufBuffer {
+    /// Dmabuf file descriptor, as produced by `DmabufStream`
+    pub fd: RawFd,
+    /// DRM format modifier negotiated for this buffer's queue
+    pub modifier: DrmFormatModifier,
+}
+
+/// Returns the DRM format modifier describing the physical layout of the
+/// buffers a stream hands out
+///
+/// Mainline V4L2 has no standard ioctl or control for a driver to report a
+/// DRM format modifier for its dmabuf-exported buffers (unlike, say, the
+/// `DRM_IOCTL_MODE_GETFB2` side of the DRM/KMS API). Every V4L2 capture
+/// driver currently shipping produces plain row-major buffers, so this
+/// always reports [`DRM_FORMAT_MOD_LINEAR`]; it exists as the single place
+/// to change if/when a driver-specific mechanism to negotiate tiled or
+/// compressed modifiers lands.
+pub fn query_format_modifier(_fd: RawFd) -> DrmFormatModifier {
+    DRM_FORMAT_MOD_LINEAR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v4l2_to_drm_known_format() {
+        assert_eq!(to_drm_fourcc(&FourCC::new(b"YUYV")), Some(DRM_FORMAT_YUYV));
+    }
+
+    #[test]
+    fn v4l2_to_drm_unknown_format() {
+        assert_eq!(to_drm_fourcc(&FourCC::new(b"ZZZZ")), None);
+    }
+
+    #[test]
+    fn drm_to_v4l2_known_format() {
+        assert_eq!(from_drm_fourcc(DRM_FORMAT_NV12), Some(FourCC::new(b"NV12")));
+    }
+
+    #[test]
+    fn rgb24_formats_account_for_drm_byte_order() {
+        // V4L2_PIX_FMT_RGB24 stores R,G,B, which is DRM_FORMAT_BGR888's
+        // in-memory layout, not DRM_FORMAT_RGB888's.
+        assert_eq!(to_drm_fourcc(&FourCC::new(b"RGB3")), Some(DRM_FORMAT_BGR888));
+        assert_eq!(to_drm_fourcc(&FourCC::new(b"BGR3")), Some(DRM_FORMAT_RGB888));
+    }
+
+    #[test]
+    fn query_format_modifier_reports_linear() {
+        assert_eq!(query_format_modifier(-1), DRM_FORMAT_MOD_LINEAR);
+    }
+}
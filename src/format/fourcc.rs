@@ -1,9 +1,20 @@
-use std::{fmt, str};
+use std::convert::TryFrom;
+use std::{error, fmt, str};
+
+/// Bit 31 of the 32-bit V4L2 fourcc code, set by the `v4l2_fourcc_be` macro
+/// to mark the big-endian variant of a packed format
+const V4L2_FOURCC_BE_FLAG: u32 = 1 << 31;
 
 #[derive(Default, Copy, Clone, Eq)]
 /// Four character code representing a pixelformat
 pub struct FourCC {
     pub repr: [u8; 4],
+    /// Whether this is the big-endian variant of the format (`v4l2_fourcc_be`).
+    /// Kept private and out of equality/display-affecting comparisons other
+    /// than `Debug`/`Display` themselves, so existing code comparing codes
+    /// with `==` keeps working regardless of endianness; use
+    /// [`FourCC::is_big_endian`] where the distinction matters.
+    big_endian: bool,
 }
 
 impl FourCC {
@@ -20,8 +31,11 @@ impl FourCC {
     /// use v4l::format::FourCC;
     /// let fourcc = FourCC::new(b"YUYV");
     /// ```
-    pub fn new(repr: &[u8; 4]) -> FourCC {
-        FourCC { repr: *repr }
+    pub const fn new(repr: &[u8; 4]) -> FourCC {
+        FourCC {
+            repr: *repr,
+            big_endian: false,
+        }
     }
 
     /// Returns the string representation of a four character code
@@ -36,6 +50,27 @@ impl FourCC {
     pub fn str(&self) -> Result<&str, str::Utf8Error> {
         str::from_utf8(&self.repr)
     }
+
+    /// Returns whether this is the big-endian variant of the format, i.e.
+    /// whether bit 31 of its `u32` representation (`v4l2_fourcc_be`) is set
+    pub fn is_big_endian(&self) -> bool {
+        self.big_endian
+    }
+
+    /// Returns a copy of this four character code with the big-endian flag
+    /// set as requested
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::format::FourCC;
+    /// let fourcc = FourCC::new(b"RGB3").with_big_endian(true);
+    /// assert!(fourcc.is_big_endian());
+    /// ```
+    pub fn with_big_endian(mut self, big_endian: bool) -> FourCC {
+        self.big_endian = big_endian;
+        self
+    }
 }
 
 impl fmt::Debug for FourCC {
@@ -44,9 +79,20 @@ impl fmt::Debug for FourCC {
         if let Ok(string) = string {
             write!(f, "FourCC(")?;
             string.fmt(f)?;
+            if self.big_endian {
+                write!(f, "-BE")?;
+            }
             write!(f, ")")?;
         } else {
-            write!(f, "FourCC({:02x} {:02x} {:02x} {:02x})", self.repr[0], self.repr[1], self.repr[2], self.repr[3])?;
+            write!(
+                f,
+                "FourCC({:02x} {:02x} {:02x} {:02x}{})",
+                self.repr[0],
+                self.repr[1],
+                self.repr[2],
+                self.repr[3],
+                if self.big_endian { "-BE" } else { "" }
+            )?;
         }
         Ok(())
     }
@@ -58,6 +104,9 @@ impl fmt::Display for FourCC {
         if let Ok(string) = string {
             write!(f, "{}", string)?;
         }
+        if self.big_endian {
+            write!(f, "-BE")?;
+        }
         Ok(())
     }
 }
@@ -70,16 +119,82 @@ impl PartialEq for FourCC {
 
 impl From<u32> for FourCC {
     fn from(code: u32) -> Self {
-        FourCC::new(&code.to_le_bytes())
+        let big_endian = code & V4L2_FOURCC_BE_FLAG != 0;
+        FourCC::new(&(code & !V4L2_FOURCC_BE_FLAG).to_le_bytes()).with_big_endian(big_endian)
     }
 }
 
 impl From<FourCC> for u32 {
     fn from(fourcc: FourCC) -> Self {
-        Self::from_le_bytes(fourcc.repr)
+        let code = Self::from_le_bytes(fourcc.repr);
+        if fourcc.big_endian {
+            code | V4L2_FOURCC_BE_FLAG
+        } else {
+            code
+        }
+    }
+}
+
+/// Error returned when a byte slice or string cannot be parsed into a [`FourCC`]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FourCCError {
+    /// The input was longer than the 4 bytes a four character code can hold
+    TooLong(usize),
+}
+
+impl fmt::Display for FourCCError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FourCCError::TooLong(len) => {
+                write!(f, "four character code must be at most 4 bytes, got {}", len)
+            }
+        }
+    }
+}
+
+impl error::Error for FourCCError {}
+
+impl TryFrom<&[u8]> for FourCC {
+    type Error = FourCCError;
+
+    /// Builds a four character code from a runtime byte slice
+    ///
+    /// Inputs shorter than 4 bytes are right-padded with ASCII spaces (the
+    /// convention used by codes such as `"Y8  "`); inputs longer than 4
+    /// bytes are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use v4l::format::FourCC;
+    /// let fourcc = FourCC::try_from(&b"YUYV"[..]).unwrap();
+    /// ```
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() > 4 {
+            return Err(FourCCError::TooLong(bytes.len()));
+        }
+
+        let mut repr = [b' '; 4];
+        repr[..bytes.len()].copy_from_slice(bytes);
+        Ok(FourCC {
+            repr,
+            big_endian: false,
+        })
     }
 }
 
+impl str::FromStr for FourCC {
+    type Err = FourCCError;
+
+    /// Parses a four character code from a string, e.g. `"YUYV".parse::<FourCC>()`
+    ///
+    /// Shorter strings are right-padded with ASCII spaces; strings longer
+    /// than 4 bytes are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FourCC::try_from(s.as_bytes())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -94,4 +209,66 @@ mod tests {
     fn debug_fourcc_nonascii() {
         assert_eq!(format!("{:?}", FourCC::new(&[0x01, 0xff, 0x20, 0xcd])), "FourCC(01 ff 20 cd)");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parse_fourcc_from_str() {
+        assert_eq!("YUYV".parse::<FourCC>().unwrap(), FourCC::new(b"YUYV"));
+    }
+
+    #[test]
+    fn parse_fourcc_pads_short_codes() {
+        assert_eq!("Y8".parse::<FourCC>().unwrap(), FourCC::new(b"Y8  "));
+    }
+
+    #[test]
+    fn parse_fourcc_rejects_long_codes() {
+        assert_eq!("TOOLONG".parse::<FourCC>(), Err(FourCCError::TooLong(7)));
+    }
+
+    #[test]
+    fn try_from_slice() {
+        assert_eq!(
+            FourCC::try_from(&b"RGB"[..]).unwrap(),
+            FourCC::new(b"RGB ")
+        );
+    }
+
+    #[test]
+    fn from_u32_strips_big_endian_flag() {
+        let code = u32::from_le_bytes(*b"RGB3") | V4L2_FOURCC_BE_FLAG;
+        let fourcc = FourCC::from(code);
+        assert_eq!(fourcc, FourCC::new(b"RGB3"));
+        assert!(fourcc.is_big_endian());
+    }
+
+    #[test]
+    fn into_u32_roundtrips_big_endian_flag() {
+        let fourcc = FourCC::new(b"RGB3").with_big_endian(true);
+        let code: u32 = fourcc.into();
+        assert_eq!(code & V4L2_FOURCC_BE_FLAG, V4L2_FOURCC_BE_FLAG);
+        let roundtripped = FourCC::from(code);
+        assert_eq!(roundtripped, fourcc);
+        assert!(roundtripped.is_big_endian());
+    }
+
+    #[test]
+    fn equality_ignores_big_endian_flag() {
+        assert_eq!(FourCC::new(b"RGB3"), FourCC::new(b"RGB3").with_big_endian(true));
+    }
+
+    #[test]
+    fn debug_fourcc_big_endian() {
+        assert_eq!(
+            format!("{:?}", FourCC::new(b"RGB3").with_big_endian(true)),
+            "FourCC(\"RGB3\"-BE)"
+        );
+    }
+
+    #[test]
+    fn display_fourcc_big_endian() {
+        assert_eq!(
+            format!("{}", FourCC::new(b"RGB3").with_big_endian(true)),
+            "RGB3-BE"
+        );
+    }
+}
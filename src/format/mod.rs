@@ -0,0 +1,6 @@
+mod fourcc;
+pub mod drm;
+pub mod pixelformat;
+
+pub use fourcc::{FourCC, FourCCError};
+pub use pixelformat::PixelFormatInfo;
@@ -0,0 +1,202 @@
+use crate::format::FourCC;
+
+/// Chroma subsampling scheme used by a YUV pixel format
+///
+/// `None` is also used for RGB and compressed formats, where the concept
+/// does not apply.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChromaSubsampling {
+    /// No subsampling (RGB, or full-resolution chroma)
+    None,
+    /// Chroma subsampled horizontally only (e.g. YUYV, UYVY)
+    Yuv422,
+    /// Chroma subsampled both horizontally and vertically (e.g. NV12, YU12)
+    Yuv420,
+}
+
+/// Static metadata describing a pixel format
+///
+/// Obtained via [`PixelFormatInfo::for_fourcc`], this lets callers size
+/// buffers and validate formats without first capturing a frame.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PixelFormatInfo {
+    /// The four character code this info describes
+    pub fourcc: FourCC,
+    /// Average bits used to store one pixel
+    pub bits_per_pixel: u32,
+    /// Chroma subsampling scheme, if any
+    pub chroma_subsampling: ChromaSubsampling,
+    /// Number of planes the format is split into (1 for packed formats)
+    pub planes: u32,
+    /// Whether the format is compressed (e.g. MJPEG), making its size
+    /// data-dependent rather than derivable from width/height alone
+    pub compressed: bool,
+}
+
+impl PixelFormatInfo {
+    /// Computes the minimum buffer size in bytes required to hold a frame
+    /// of this format at the given dimensions
+    ///
+    /// Returns `None` for compressed formats, since their size depends on
+    /// the encoded content rather than just width and height. The
+    /// width × height × bits-per-pixel product and the result are computed
+    /// in `u64`, so large frame dimensions do not overflow `u32` arithmetic.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use v4l::format::FourCC;
+    /// use v4l::format::pixelformat::PixelFormatInfo;
+    /// let info = PixelFormatInfo::for_fourcc(&FourCC::new(b"YUYV")).unwrap();
+    /// assert_eq!(info.min_buffer_size(640, 480), Some(640 * 480 * 2));
+    /// ```
+    pub fn min_buffer_size(&self, width: u32, height: u32) -> Option<u64> {
+        if self.compressed {
+            return None;
+        }
+        Some(width as u64 * height as u64 * self.bits_per_pixel as u64 / 8)
+    }
+
+    /// Looks up the static metadata for a known pixel format
+    ///
+    /// Returns `None` if the format is not in the catalog. Matches on the
+    /// four character code alone; the catalog does not distinguish
+    /// big-endian variants, so `fourcc.is_big_endian()` is ignored.
+    pub fn for_fourcc(fourcc: &FourCC) -> Option<PixelFormatInfo> {
+        PIXELFORMAT_CATALOG
+            .iter()
+            .find(|info| info.fourcc.repr == fourcc.repr)
+            .copied()
+    }
+}
+
+/// Named constants for commonly used V4L2 pixel formats
+impl FourCC {
+    pub const YUYV: FourCC = FourCC::new(b"YUYV");
+    pub const UYVY: FourCC = FourCC::new(b"UYVY");
+    pub const NV12: FourCC = FourCC::new(b"NV12");
+    pub const NV21: FourCC = FourCC::new(b"NV21");
+    pub const YU12: FourCC = FourCC::new(b"YU12");
+    pub const RGB3: FourCC = FourCC::new(b"RGB3");
+    pub const BGR3: FourCC = FourCC::new(b"BGR3");
+    pub const BA24: FourCC = FourCC::new(b"BA24");
+    pub const GREY: FourCC = FourCC::new(b"GREY");
+    pub const MJPG: FourCC = FourCC::new(b"MJPG");
+}
+
+/// Curated static table of [`PixelFormatInfo`] for commonly used formats,
+/// mirroring VLC's generated fourcc table rather than hand-written match
+/// arms scattered across call sites
+const PIXELFORMAT_CATALOG: &[PixelFormatInfo] = &[
+    PixelFormatInfo {
+        fourcc: FourCC::YUYV,
+        bits_per_pixel: 16,
+        chroma_subsampling: ChromaSubsampling::Yuv422,
+        planes: 1,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::UYVY,
+        bits_per_pixel: 16,
+        chroma_subsampling: ChromaSubsampling::Yuv422,
+        planes: 1,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::NV12,
+        bits_per_pixel: 12,
+        chroma_subsampling: ChromaSubsampling::Yuv420,
+        planes: 2,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::NV21,
+        bits_per_pixel: 12,
+        chroma_subsampling: ChromaSubsampling::Yuv420,
+        planes: 2,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::YU12,
+        bits_per_pixel: 12,
+        chroma_subsampling: ChromaSubsampling::Yuv420,
+        planes: 3,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::RGB3,
+        bits_per_pixel: 24,
+        chroma_subsampling: ChromaSubsampling::None,
+        planes: 1,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::BGR3,
+        bits_per_pixel: 24,
+        chroma_subsampling: ChromaSubsampling::None,
+        planes: 1,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::BA24,
+        bits_per_pixel: 32,
+        chroma_subsampling: ChromaSubsampling::None,
+        planes: 1,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::GREY,
+        bits_per_pixel: 8,
+        chroma_subsampling: ChromaSubsampling::None,
+        planes: 1,
+        compressed: false,
+    },
+    PixelFormatInfo {
+        fourcc: FourCC::MJPG,
+        bits_per_pixel: 0,
+        chroma_subsampling: ChromaSubsampling::None,
+        planes: 1,
+        compressed: true,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_known_format() {
+        let info = PixelFormatInfo::for_fourcc(&FourCC::YUYV).unwrap();
+        assert_eq!(info.bits_per_pixel, 16);
+        assert_eq!(info.chroma_subsampling, ChromaSubsampling::Yuv422);
+    }
+
+    #[test]
+    fn lookup_unknown_format() {
+        assert_eq!(PixelFormatInfo::for_fourcc(&FourCC::new(b"ZZZZ")), None);
+    }
+
+    #[test]
+    fn min_buffer_size_packed() {
+        let info = PixelFormatInfo::for_fourcc(&FourCC::YUYV).unwrap();
+        assert_eq!(info.min_buffer_size(640, 480), Some(640 * 480 * 2));
+    }
+
+    #[test]
+    fn min_buffer_size_compressed_is_unknown() {
+        let info = PixelFormatInfo::for_fourcc(&FourCC::MJPG).unwrap();
+        assert_eq!(info.min_buffer_size(640, 480), None);
+    }
+
+    #[test]
+    fn min_buffer_size_does_not_overflow_at_high_resolution() {
+        let info = PixelFormatInfo::for_fourcc(&FourCC::BA24).unwrap();
+        assert_eq!(info.min_buffer_size(3840, 2160), Some(3840 * 2160 * 4));
+    }
+
+    #[test]
+    fn lookup_ignores_big_endian_flag() {
+        let be = FourCC::YUYV.with_big_endian(true);
+        assert_eq!(PixelFormatInfo::for_fourcc(&be), PixelFormatInfo::for_fourcc(&FourCC::YUYV));
+    }
+}
@@ -0,0 +1,109 @@
+use std::io;
+use std::os::unix::io::RawFd;
+
+use v4l2_sys::{
+    v4l2_buffer, v4l2_plane, V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE, V4L2_MEMORY_DMABUF, VIDIOC_DQBUF,
+    VIDIOC_QBUF,
+};
+
+use crate::buffer::Metadata;
+use crate::format::drm::{query_format_modifier, DmabufBuffer, DrmFormatModifier};
+use crate::io::plane::PlaneMemory;
+use crate::io::traits::CaptureStream;
+
+/// Dmabuf-backed capture stream
+///
+/// Captures into caller-supplied dmabuf fds (`V4L2_MEMORY_DMABUF`) instead
+/// of mmap'd buffers, so each fd can be handed directly to a GPU/encoder
+/// import path without a copy. `next()` performs the real
+/// `VIDIOC_DQBUF`/`VIDIOC_QBUF` round trip and builds [`Metadata`] straight
+/// from the `v4l2_buffer`/`v4l2_plane` the driver filled in, rather than
+/// re-deriving the buffer size by mmap'ing the fd.
+///
+/// This wraps a queue that has already been negotiated elsewhere (format
+/// set, `VIDIOC_REQBUFS` run, and every buffer's dmabuf fd(s) queued once
+/// via `VIDIOC_QBUF`); `new` only takes over the steady-state
+/// dequeue/requeue loop.
+pub struct DmabufStream {
+    fd: RawFd,
+    buf_type: u32,
+    /// Dmabuf fd(s) backing each buffer, `planes_per_buffer` per buffer,
+    /// indexed the same way as the driver's `v4l2_buffer.index`.
+    fds: Vec<RawFd>,
+    planes_per_buffer: usize,
+    modifier: DrmFormatModifier,
+}
+
+impl DmabufStream {
+    /// Wraps an already-negotiated, already-queued capture queue on `fd`
+    pub fn new(fd: RawFd, buf_type: u32, fds: Vec<RawFd>, planes_per_buffer: usize) -> Self {
+        let modifier = query_format_modifier(fd);
+        DmabufStream {
+            fd,
+            buf_type,
+            fds,
+            planes_per_buffer,
+            modifier,
+        }
+    }
+
+    fn fds_for_index(&self, index: usize) -> &[RawFd] {
+        let start = index * self.planes_per_buffer;
+        &self.fds[start..start + self.planes_per_buffer]
+    }
+}
+
+impl CaptureStream for DmabufStream {
+    fn next(&mut self) -> io::Result<(Vec<DmabufBuffer>, Metadata)> {
+        let is_mplane = self.buf_type == V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE;
+
+        let mut buf: v4l2_buffer = unsafe { std::mem::zeroed() };
+        buf.type_ = self.buf_type;
+        buf.memory = V4L2_MEMORY_DMABUF;
+
+        let mut mplanes: Vec<v4l2_plane> = Vec::new();
+        if is_mplane {
+            mplanes = vec![unsafe { std::mem::zeroed() }; self.planes_per_buffer];
+            buf.m.planes = mplanes.as_mut_ptr();
+            buf.length = mplanes.len() as u32;
+        }
+
+        if unsafe { libc::ioctl(self.fd, VIDIOC_DQBUF as _, &mut buf) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let index = buf.index as usize;
+        let fds = self.fds_for_index(index).to_vec();
+
+        let metadata = if is_mplane {
+            Metadata::from_v4l2_buffer_mplane(&buf, &mplanes, |i| PlaneMemory::Fd(fds[i]))
+        } else {
+            Metadata::from_v4l2_buffer(&buf, PlaneMemory::Fd(fds[0]))
+        };
+
+        let buffers = fds
+            .iter()
+            .map(|&fd| DmabufBuffer {
+                fd,
+                modifier: self.modifier,
+            })
+            .collect();
+
+        // Re-associate the same dmabuf fd(s) with this buffer index and
+        // hand it back to the driver so the queue keeps streaming.
+        if is_mplane {
+            for (plane, &fd) in mplanes.iter_mut().zip(fds.iter()) {
+                plane.m.fd = fd;
+            }
+            buf.m.planes = mplanes.as_mut_ptr();
+        } else {
+            buf.m.fd = fds[0];
+        }
+
+        if unsafe { libc::ioctl(self.fd, VIDIOC_QBUF as _, &mut buf) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok((buffers, metadata))
+    }
+}
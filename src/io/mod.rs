@@ -0,0 +1,6 @@
+pub mod dmabuf;
+pub mod plane;
+pub mod traits;
+
+pub use dmabuf::DmabufStream;
+pub use traits::CaptureStream;
@@ -0,0 +1,57 @@
+use std::os::unix::io::RawFd;
+
+use v4l2_sys::{v4l2_buffer, v4l2_plane};
+
+/// Where the backing memory of a plane lives
+///
+/// Dmabuf-backed planes, single-planar or multiplanar, each carry a file
+/// descriptor; `V4L2_MEMORY_MMAP` buffers instead carry an offset into the
+/// device's mmap region.
+#[derive(Debug, Copy, Clone)]
+pub enum PlaneMemory {
+    /// Dmabuf file descriptor, as produced by `DmabufStream`
+    Fd(RawFd),
+    /// Offset into the mmap'd region of the device
+    Offset(u32),
+}
+
+/// Describes a single plane of a captured buffer
+///
+/// For single-planar formats (`V4L2_BUF_TYPE_VIDEO_CAPTURE`), a frame
+/// carries exactly one `PlaneDescriptor`. For multiplanar formats
+/// (`V4L2_BUF_TYPE_VIDEO_CAPTURE_MPLANE`, e.g. NV12 or YUV420M on SoC
+/// ISPs), a frame carries one descriptor per `v4l2_plane`, in the order
+/// reported by the driver.
+#[derive(Debug, Copy, Clone)]
+pub struct PlaneDescriptor {
+    /// Dmabuf fd or mmap offset backing this plane
+    pub fd_or_offset: PlaneMemory,
+    /// Number of bytes actually written by the driver for this plane
+    pub bytesused: u32,
+    /// Total allocated length of this plane
+    pub length: u32,
+    /// Offset of the plane's data within its backing memory
+    pub data_offset: u32,
+}
+
+impl PlaneDescriptor {
+    /// Builds the single plane descriptor for a non-multiplanar buffer
+    pub(crate) fn from_v4l2_buffer(buf: &v4l2_buffer, fd_or_offset: PlaneMemory) -> Self {
+        PlaneDescriptor {
+            fd_or_offset,
+            bytesused: buf.bytesused,
+            length: buf.length,
+            data_offset: 0,
+        }
+    }
+
+    /// Builds a plane descriptor for one plane of a multiplanar buffer
+    pub(crate) fn from_v4l2_plane(plane: &v4l2_plane, fd_or_offset: PlaneMemory) -> Self {
+        PlaneDescriptor {
+            fd_or_offset,
+            bytesused: plane.bytesused,
+            length: plane.length,
+            data_offset: plane.data_offset,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use std::io;
+
+use crate::buffer::Metadata;
+use crate::format::drm::DmabufBuffer;
+
+/// Common interface for streams that hand out buffers from a capture queue
+///
+/// Implemented by [`DmabufStream`](crate::io::dmabuf::DmabufStream); buffers
+/// are handed out as [`DmabufBuffer`]s.
+pub trait CaptureStream {
+    /// Dequeues the next available buffer, blocking until the driver fills
+    /// one
+    ///
+    /// Returns one [`DmabufBuffer`] per plane (exactly one for
+    /// single-planar formats) alongside the [`Metadata`] describing the
+    /// buffer as a whole, then re-queues the buffer for the driver to fill
+    /// again.
+    fn next(&mut self) -> io::Result<(Vec<DmabufBuffer>, Metadata)>;
+}